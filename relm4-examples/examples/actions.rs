@@ -1,7 +1,4 @@
-use gtk::gio;
-use gtk::prelude::{
-    ActionMapExt, BoxExt, ButtonExt, GtkApplicationExt, GtkWindowExt, OrientableExt, WidgetExt,
-};
+use gtk::prelude::{BoxExt, ButtonExt, GtkWindowExt, OrientableExt, WidgetExt};
 use relm4::{send, AppUpdate, Model, RelmApp, Sender, WidgetPlus, Widgets};
 
 #[derive(Default)]
@@ -41,6 +38,13 @@ impl Widgets<AppModel, ()> for AppWidgets {
             set_title: Some("Simple app"),
             set_default_width: 300,
             set_default_height: 100,
+
+            actions("win") {
+                test(["<primary>W"]) => move |_, _| {
+                    println!("ACTION!");
+                },
+            },
+
             set_child = Some(&gtk::Box) {
                 set_orientation: gtk::Orientation::Vertical,
                 set_margin_all: 5,
@@ -64,20 +68,6 @@ impl Widgets<AppModel, ()> for AppWidgets {
             },
         }
     }
-
-    fn post_init() {
-        let action = gio::SimpleAction::new("test", None);
-        action.connect_activate(|_, _| {
-            println!("ACTION!");
-        });
-
-        let app = relm4::gtk_application();
-        app.set_accels_for_action("win.test", &["<primary>W"]);
-
-        let actions = gio::SimpleActionGroup::new();
-        main_window.insert_action_group("win", Some(&actions));
-        actions.add_action(&action);
-    }
 }
 
 fn main() {
@@ -0,0 +1,149 @@
+use gtk::prelude::{BoxExt, ButtonExt, GtkWindowExt, OrientableExt, WidgetExt};
+use relm4::{
+    send, AppUpdate, ComponentUpdate, Model, RelmApp, RelmComponent, Sender, WidgetPlus, Widgets,
+};
+
+#[derive(Default)]
+struct AppModel {
+    counter: u8,
+}
+
+enum AppMsg {
+    Increment,
+    Decrement,
+}
+
+impl Model for AppModel {
+    type Msg = AppMsg;
+    type Widgets = AppWidgets;
+    type Components = AppComponents;
+}
+
+impl AppUpdate for AppModel {
+    fn update(&mut self, msg: AppMsg, _components: &AppComponents, _sender: Sender<AppMsg>) -> bool {
+        match msg {
+            AppMsg::Increment => {
+                self.counter = self.counter.wrapping_add(1);
+            }
+            AppMsg::Decrement => {
+                self.counter = self.counter.wrapping_sub(1);
+            }
+        }
+        true
+    }
+}
+
+#[derive(Default)]
+struct SidebarModel {
+    clicks: u8,
+}
+
+enum SidebarMsg {
+    Clicked,
+}
+
+impl Model for SidebarModel {
+    type Msg = SidebarMsg;
+    type Widgets = SidebarWidgets;
+    type Components = ();
+}
+
+impl ComponentUpdate<AppModel> for SidebarModel {
+    fn init_model(_parent_model: &AppModel) -> Self {
+        SidebarModel::default()
+    }
+
+    fn update(
+        &mut self,
+        msg: SidebarMsg,
+        _components: &(),
+        _sender: Sender<SidebarMsg>,
+        _parent_sender: Sender<AppMsg>,
+    ) {
+        match msg {
+            SidebarMsg::Clicked => self.clicks = self.clicks.wrapping_add(1),
+        }
+    }
+}
+
+#[relm4_macros::widget]
+impl Widgets<SidebarModel, AppModel> for SidebarWidgets {
+    view! {
+        gtk::Box {
+            set_orientation: gtk::Orientation::Vertical,
+            set_spacing: 5,
+
+            append = &gtk::Button {
+                set_label: "Sidebar button",
+                connect_clicked(sender) => move |_| {
+                    send!(sender, SidebarMsg::Clicked);
+                },
+            },
+        }
+    }
+}
+
+struct AppComponents {
+    sidebar: RelmComponent<SidebarModel, AppModel>,
+}
+
+impl relm4::Components<AppModel> for AppComponents {
+    fn init_components(parent_model: &AppModel, parent_sender: Sender<AppMsg>) -> Self {
+        AppComponents {
+            sidebar: RelmComponent::new(parent_model, parent_sender),
+        }
+    }
+}
+
+// A widget with more than one insertion point: the sidebar and the content area
+// are both registered with `#[container]` so that components can be attached to
+// either one through `Widgets::container_widget`, instead of always the root.
+// The sidebar component is attached with `component!`, which routes its root
+// widget through the "sidebar" container rather than the window's content box.
+#[relm4_macros::widget]
+impl Widgets<AppModel, ()> for AppWidgets {
+    view! {
+        main_window = gtk::ApplicationWindow {
+            set_title: Some("Named containers"),
+            set_default_width: 400,
+            set_default_height: 200,
+            set_child = Some(&gtk::Box) {
+                set_orientation: gtk::Orientation::Horizontal,
+
+                #[container = "sidebar"]
+                append = &gtk::Box {
+                    set_orientation: gtk::Orientation::Vertical,
+                    set_width_request: 100,
+                },
+
+                append = component!(components.sidebar, container = "sidebar"),
+
+                #[container = "content"]
+                append = &gtk::Box {
+                    set_orientation: gtk::Orientation::Vertical,
+                    set_spacing: 5,
+
+                    append = &gtk::Button {
+                        set_label: "Increment",
+                        connect_clicked(sender) => move |_| {
+                            send!(sender, AppMsg::Increment);
+                        },
+                    },
+                    append = &gtk::Button {
+                        set_label: "Decrement",
+                        connect_clicked(sender) => move |_| {
+                            send!(sender, AppMsg::Decrement);
+                        },
+                    },
+                },
+            },
+        }
+    }
+}
+
+fn main() {
+    let model = AppModel::default();
+
+    let app = RelmApp::new(model);
+    app.run();
+}
@@ -0,0 +1,82 @@
+use gtk::prelude::{BoxExt, ButtonExt, GtkWindowExt, OrientableExt};
+use relm4::{send, AppUpdate, Model, RelmApp, Sender, WidgetPlus, Widgets};
+use tracker::track;
+
+// `#[tracker::track]` generates a hidden bitfield plus a `changed(mask)` method and,
+// for every field, a const fn returning that field's bit (e.g. `AppModel::counter()`).
+#[track]
+#[derive(Default)]
+struct AppModel {
+    counter: u8,
+}
+
+enum AppMsg {
+    Increment,
+    Decrement,
+}
+
+impl Model for AppModel {
+    type Msg = AppMsg;
+    type Widgets = AppWidgets;
+    type Components = ();
+}
+
+impl AppUpdate for AppModel {
+    fn update(&mut self, msg: AppMsg, _components: &(), _sender: Sender<AppMsg>) -> bool {
+        // `view()` only looks at fields since the last `reset()`, so clear the
+        // bitfield up front: every `track!` assignment below will re-run only if
+        // this update actually changes the field it names.
+        self.reset();
+
+        match msg {
+            AppMsg::Increment => {
+                self.set_counter(self.counter.wrapping_add(1));
+            }
+            AppMsg::Decrement => {
+                self.set_counter(self.counter.wrapping_sub(1));
+            }
+        }
+        true
+    }
+}
+
+#[relm4_macros::widget]
+impl Widgets<AppModel, ()> for AppWidgets {
+    view! {
+        gtk::ApplicationWindow {
+            set_title: Some("Tracker integration"),
+            set_default_width: 300,
+            set_default_height: 100,
+            set_child = Some(&gtk::Box) {
+                set_orientation: gtk::Orientation::Vertical,
+                set_margin_all: 5,
+                set_spacing: 5,
+
+                append = &gtk::Button {
+                    set_label: "Increment",
+                    connect_clicked(sender) => move |_| {
+                        send!(sender, AppMsg::Increment);
+                    },
+                },
+                append = &gtk::Button {
+                    set_label: "Decrement",
+                    connect_clicked(sender) => move |_| {
+                        send!(sender, AppMsg::Decrement);
+                    },
+                },
+                append = &gtk::Label {
+                    set_margin_all: 5,
+                    // Only re-assigned when `counter` actually changed.
+                    set_label: track!(model.counter, &format!("Counter: {}", model.counter)),
+                }
+            },
+        }
+    }
+}
+
+fn main() {
+    let model = AppModel::default();
+
+    let app = RelmApp::new(model);
+    app.run();
+}
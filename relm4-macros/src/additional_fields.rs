@@ -0,0 +1,32 @@
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{quote, ToTokens};
+use syn::parse::{Parse, ParseStream};
+use syn::punctuated::Punctuated;
+use syn::{braced, token, Field, Token};
+
+/// Extra struct fields declared with `additional_fields! { .. }`, stored verbatim
+/// alongside the generated widget fields (e.g. plain state that doesn't correspond
+/// to a widget, such as a `gtk::gio::SimpleActionGroup`).
+pub(super) struct AdditionalFields {
+    _brace_token: token::Brace,
+    pub inner: Punctuated<Field, Token![,]>,
+}
+
+impl Parse for AdditionalFields {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let content;
+        let brace_token = braced!(content in input);
+        let inner = content.parse_terminated(Field::parse_named)?;
+        Ok(AdditionalFields {
+            _brace_token: brace_token,
+            inner,
+        })
+    }
+}
+
+impl ToTokens for AdditionalFields {
+    fn to_tokens(&self, tokens: &mut TokenStream2) {
+        let fields = &self.inner;
+        tokens.extend(quote! { #fields });
+    }
+}
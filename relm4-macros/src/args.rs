@@ -0,0 +1,18 @@
+use syn::parse::{Parse, ParseStream};
+use syn::{Ident, Token};
+
+/// A single `name = value` argument, as used in attribute lists such as
+/// `#[widget(relm4 = ::myapp::relm4)]` or `#[container = "sidebar"]`.
+pub(super) struct NamedArg<T> {
+    pub name: Ident,
+    pub value: T,
+}
+
+impl<T: Parse> Parse for NamedArg<T> {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let name: Ident = input.parse()?;
+        input.parse::<Token![=]>()?;
+        let value: T = input.parse()?;
+        Ok(NamedArg { name, value })
+    }
+}
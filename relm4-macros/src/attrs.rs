@@ -0,0 +1,76 @@
+use syn::parse::{Parse, ParseStream};
+use syn::spanned::Spanned;
+use syn::{Ident, LitStr, Path, Token, Visibility};
+
+/// Arguments passed to the `#[widget(..)]` attribute itself, e.g. `#[widget(pub)]`
+/// or `#[widget(relm4 = ::myapp::relm4)]`.
+pub(super) struct Attrs {
+    pub visibility: Visibility,
+    pub relm4_path: Path,
+}
+
+impl Default for Attrs {
+    fn default() -> Self {
+        Attrs {
+            visibility: Visibility::Inherited,
+            relm4_path: syn::parse_quote! { relm4 },
+        }
+    }
+}
+
+impl Parse for Attrs {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let mut attrs = Attrs::default();
+
+        while !input.is_empty() {
+            if input.peek(Token![pub]) {
+                attrs.visibility = input.parse()?;
+            } else {
+                let ident: Ident = input.parse()?;
+                input.parse::<Token![=]>()?;
+                match ident.to_string().as_str() {
+                    "visibility" => attrs.visibility = input.parse()?,
+                    "relm4" => attrs.relm4_path = input.parse()?,
+                    other => {
+                        return Err(syn::Error::new_spanned(
+                            ident,
+                            format!("Unknown `widget` attribute `{}`", other),
+                        ))
+                    }
+                }
+            }
+
+            if !input.is_empty() {
+                input.parse::<Token![,]>()?;
+            }
+        }
+
+        Ok(attrs)
+    }
+}
+
+/// Attributes attached to a single widget inside `view!`, such as
+/// `#[container = "sidebar"]`, which registers the widget as a named insertion
+/// point returned from `container_widget`.
+#[derive(Default, Clone)]
+pub(super) struct WidgetAttr {
+    pub container: Option<LitStr>,
+}
+
+impl WidgetAttr {
+    /// Merges a single already parsed `#[name = "value"]` attribute attached to
+    /// a widget into `self`. Returns an error for any attribute name other than
+    /// the ones recognized here.
+    pub(super) fn merge(&mut self, ident: &Ident, value: LitStr) -> syn::Result<()> {
+        match ident.to_string().as_str() {
+            "container" => self.container = Some(value),
+            other => {
+                return Err(syn::Error::new(
+                    value.span(),
+                    format!("Unknown widget attribute `{}`", other),
+                ))
+            }
+        }
+        Ok(())
+    }
+}
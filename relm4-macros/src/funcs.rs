@@ -0,0 +1,46 @@
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{Error, ItemFn};
+
+/// The `fn pre_init() { .. }`-style hooks that may appear inside a `#[widget]` impl
+/// block, alongside the `view!`/`additional_fields!` macros.
+#[derive(Default)]
+pub(super) struct Funcs {
+    pub pre_init: TokenStream2,
+    pub post_init: TokenStream2,
+    pub pre_connect_components: TokenStream2,
+    pub post_connect_components: TokenStream2,
+    pub manual_view: TokenStream2,
+}
+
+impl Funcs {
+    pub(super) fn new(funcs: &[ItemFn]) -> Result<Self, Error> {
+        let mut out = Funcs::default();
+
+        for func in funcs {
+            let stmts = &func.block.stmts;
+            let stream = quote! { #(#stmts)* };
+
+            match func.sig.ident.to_string().as_str() {
+                "pre_init" => out.pre_init = stream,
+                "post_init" => out.post_init = stream,
+                "pre_connect_components" => out.pre_connect_components = stream,
+                "post_connect_components" => out.post_connect_components = stream,
+                "manual_view" => out.manual_view = stream,
+                other => {
+                    return Err(Error::new_spanned(
+                        &func.sig.ident,
+                        format!(
+                            "Unknown function `{}` in widget impl (expected one of \
+                             pre_init, post_init, pre_connect_components, \
+                             post_connect_components, manual_view)",
+                            other
+                        ),
+                    ))
+                }
+            }
+        }
+
+        Ok(out)
+    }
+}
@@ -0,0 +1,67 @@
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::ToTokens;
+use syn::parse::{Parse, ParseStream};
+use syn::{braced, Attribute, Generics, ItemFn, Macro, Path, Token, Type, WhereClause};
+
+/// The attributes on the `impl` block, kept around verbatim so they can be
+/// re-emitted on the generated struct (e.g. `#[cfg(feature = "...")]`).
+#[derive(Default)]
+pub(super) struct OuterAttrs(Vec<Attribute>);
+
+impl ToTokens for OuterAttrs {
+    fn to_tokens(&self, tokens: &mut TokenStream2) {
+        for attr in &self.0 {
+            attr.to_tokens(tokens);
+        }
+    }
+}
+
+/// The `impl Widgets<Model, ParentModel> for AppWidgets { .. }` block passed to `#[widget]`.
+pub(super) struct ItemImpl {
+    pub outer_attrs: OuterAttrs,
+    pub impl_generics: Generics,
+    pub trait_: Path,
+    pub self_ty: Type,
+    pub where_clause: Option<WhereClause>,
+    pub macros: Vec<Macro>,
+    pub funcs: Vec<ItemFn>,
+    pub brace_span: Option<Span>,
+}
+
+impl Parse for ItemImpl {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let outer_attrs = OuterAttrs(input.call(Attribute::parse_outer)?);
+        input.parse::<Token![impl]>()?;
+        let impl_generics: Generics = input.parse()?;
+        let trait_: Path = input.parse()?;
+        input.parse::<Token![for]>()?;
+        let self_ty: Type = input.parse()?;
+        let where_clause = input.parse()?;
+
+        let content;
+        let brace = braced!(content in input);
+
+        let mut macros = Vec::new();
+        let mut funcs = Vec::new();
+
+        while !content.is_empty() {
+            if content.peek(Token![fn]) || content.peek(Token![pub]) {
+                funcs.push(content.parse()?);
+            } else {
+                macros.push(content.parse()?);
+                content.parse::<Option<Token![;]>>()?;
+            }
+        }
+
+        Ok(ItemImpl {
+            outer_attrs,
+            impl_generics,
+            trait_,
+            self_ty,
+            where_clause,
+            macros,
+            funcs,
+            brace_span: Some(brace.span),
+        })
+    }
+}
@@ -199,15 +199,16 @@ pub fn widget(attributes: TokenStream, input: TokenStream) -> TokenStream {
     let mut property_stream = TokenStream2::new();
     let mut view_stream = TokenStream2::new();
     let mut connect_stream = TokenStream2::new();
+    let mut action_stream = TokenStream2::new();
     let mut track_stream = TokenStream2::new();
     let mut component_stream = TokenStream2::new();
     let mut connect_component_stream = TokenStream2::new();
+    let mut container_stream = TokenStream2::new();
 
     for widget in widget_list {
         let w_name = &widget.name;
         let w_ty = widget.func.type_token_stream();
         let w_span = widget.func.span();
-        let w_func = widget.func.func_token_stream();
 
         struct_stream.extend(quote_spanned! {
             w_span =>
@@ -215,17 +216,17 @@ pub fn widget(attributes: TokenStream, input: TokenStream) -> TokenStream {
             #visibility #w_name: #w_ty,
         });
 
-        init_stream.extend(quote_spanned! {
-            w_span => let #w_name = #w_func;
-        });
+        init_stream.extend(widget.init_stream());
 
         return_stream.extend(widget.return_stream());
         widget.property_assign_stream(&relm4_path, &mut property_stream);
         widget.view_stream(&relm4_path, &mut view_stream);
         connect_stream.extend(widget.connect_stream());
+        action_stream.extend(widget.action_stream(&relm4_path));
         track_stream.extend(widget.track_stream(model_ty));
         component_stream.extend(widget.component_stream());
         connect_component_stream.extend(widget.connect_component_stream());
+        container_stream.extend(widget.container_stream());
     }
 
     let impl_generics = data.impl_generics;
@@ -260,6 +261,7 @@ pub fn widget(attributes: TokenStream, input: TokenStream) -> TokenStream {
                 #init_stream
                 #property_stream
                 #connect_stream
+                #action_stream
                 #post_init
                 Self {
                     #return_stream
@@ -279,6 +281,15 @@ pub fn widget(attributes: TokenStream, input: TokenStream) -> TokenStream {
                 self.#root_widget_name.clone()
             }
 
+            /// Returns the widget registered as a named insertion point via
+            /// `#[container = "name"]`, if any widget in the tree was annotated with it.
+            fn container_widget(&self, name: &str) -> Option<gtk::Widget> {
+                match name {
+                    #container_stream
+                    _ => None,
+                }
+            }
+
             /// Update the view to represent the updated model.
             fn view(&mut self, model: &#model, sender: #relm4_path::Sender<<#model as #relm4_path::Model>::Msg>) {
                 #manual_view
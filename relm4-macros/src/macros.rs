@@ -0,0 +1,43 @@
+use proc_macro2::Span;
+use syn::{Error, Macro};
+
+use crate::additional_fields::AdditionalFields;
+use crate::widgets::Widget;
+
+/// The `view! { .. }` and, optionally, `additional_fields! { .. }` macros that make
+/// up the body of a `#[widget]` impl.
+pub(super) struct Macros {
+    pub widgets: Widget,
+    pub additional_fields: Option<AdditionalFields>,
+}
+
+impl Macros {
+    pub(super) fn new(macros: &[Macro], brace_span: Span) -> Result<Self, Error> {
+        let mut widgets = None;
+        let mut additional_fields = None;
+
+        for mac in macros {
+            let ident = mac.path.segments.last().map(|segment| segment.ident.to_string());
+            match ident.as_deref() {
+                Some("view") => widgets = Some(syn::parse2(mac.tokens.clone())?),
+                Some("additional_fields") => {
+                    additional_fields = Some(syn::parse2(mac.tokens.clone())?)
+                }
+                _ => {
+                    return Err(Error::new_spanned(
+                        &mac.path,
+                        "Expected a `view!` or `additional_fields!` macro",
+                    ))
+                }
+            }
+        }
+
+        let widgets = widgets
+            .ok_or_else(|| Error::new(brace_span, "Expected a `view!` macro in the widget impl"))?;
+
+        Ok(Macros {
+            widgets,
+            additional_fields,
+        })
+    }
+}
@@ -0,0 +1,37 @@
+use syn::spanned::Spanned;
+use syn::{AngleBracketedGenericArguments, Error, GenericArgument, Type};
+
+/// The model and parent model types extracted from the `Widgets<Model, ParentModel>`
+/// trait generics of the impl block passed to `#[widget]`.
+pub(super) struct ModelTypes {
+    pub model: Type,
+    pub parent_model: Type,
+}
+
+impl ModelTypes {
+    pub(super) fn new(generics: &AngleBracketedGenericArguments) -> Result<Self, Error> {
+        let mut args = generics.args.iter();
+
+        let model = match args.next() {
+            Some(GenericArgument::Type(ty)) => ty.clone(),
+            _ => {
+                return Err(Error::new(
+                    generics.span(),
+                    "Expected a model type as the first generic parameter of `Widgets<Model, ParentModel>`",
+                ))
+            }
+        };
+
+        let parent_model = match args.next() {
+            Some(GenericArgument::Type(ty)) => ty.clone(),
+            _ => {
+                return Err(Error::new(
+                    generics.span(),
+                    "Expected a parent model type as the second generic parameter of `Widgets<Model, ParentModel>`",
+                ))
+            }
+        };
+
+        Ok(ModelTypes { model, parent_model })
+    }
+}
@@ -0,0 +1,764 @@
+//! Parsing and code generation for the widget tree declared inside `view! { .. }`.
+
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{quote, ToTokens};
+use syn::parse::discouraged::Speculative;
+use syn::parse::{Parse, ParseStream, Parser};
+use syn::punctuated::Punctuated;
+use syn::{
+    braced, parenthesized, token, Attribute, Error, Expr, Ident, LitStr, Member, Path, Token,
+};
+
+use crate::attrs::WidgetAttr;
+use crate::util::idents_to_snake_case;
+
+/// How a widget's constructor expression was wrapped, so the same wrapper can be
+/// re-applied around its variable name when attaching it to its parent, e.g.
+/// `set_child = Some(&gtk::Box) { .. }` attaches as `Some(&_gtk_box_0)`.
+#[derive(Clone)]
+enum Wrapper {
+    None,
+    Ref,
+    SomeRef,
+}
+
+/// The constructor for a widget, e.g. a bare type (`gtk::Box`, lowered to
+/// `gtk::Box::default()`) or a full call expression (`gtk::Button::with_label("x")`),
+/// optionally wrapped in `&` or `Some(&..)`.
+pub(super) struct WidgetFunc {
+    wrapper: Wrapper,
+    ty: Path,
+    ctor: Option<Expr>,
+}
+
+impl WidgetFunc {
+    pub(super) fn type_token_stream(&self) -> TokenStream2 {
+        let ty = &self.ty;
+        quote! { #ty }
+    }
+
+    pub(super) fn func_token_stream(&self) -> TokenStream2 {
+        match &self.ctor {
+            Some(expr) => quote! { #expr },
+            None => {
+                let ty = &self.ty;
+                quote! { #ty::default() }
+            }
+        }
+    }
+
+    /// Re-applies this widget's wrapper around `name`: `Some(&name)` for `Some(&..)`,
+    /// and `&name` both for `&..` and for a constructor without a wrapper.
+    fn wrap(&self, name: &Ident) -> TokenStream2 {
+        match self.wrapper {
+            Wrapper::None | Wrapper::Ref => quote! { &#name },
+            Wrapper::SomeRef => quote! { Some(&#name) },
+        }
+    }
+
+    fn parse_inner(input: ParseStream) -> syn::Result<(Path, Option<Expr>)> {
+        let fork = input.fork();
+        let path: Path = fork.parse()?;
+        if fork.is_empty() || fork.peek(token::Brace) {
+            input.advance_to(&fork);
+            return Ok((path, None));
+        }
+
+        let expr: Expr = input.parse()?;
+        let ty = match &expr {
+            Expr::Call(call) => match &*call.func {
+                Expr::Path(func_path) => {
+                    let mut ty = func_path.path.clone();
+                    ty.segments.pop();
+                    // `pop` leaves the `::` before the removed segment behind.
+                    if let Some(last) = ty.segments.pop() {
+                        ty.segments.push(last.into_value());
+                    }
+                    ty
+                }
+                _ => path,
+            },
+            _ => path,
+        };
+        Ok((ty, Some(expr)))
+    }
+}
+
+impl ToTokens for WidgetFunc {
+    fn to_tokens(&self, tokens: &mut TokenStream2) {
+        tokens.extend(self.func_token_stream());
+    }
+}
+
+impl Parse for WidgetFunc {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        if input.peek(Ident) && input.peek2(token::Paren) {
+            let fork = input.fork();
+            let ident: Ident = fork.parse()?;
+            if ident == "Some" {
+                input.advance_to(&fork);
+                let content;
+                parenthesized!(content in input);
+                content.parse::<Token![&]>()?;
+                let (ty, ctor) = WidgetFunc::parse_inner(&content)?;
+                return Ok(WidgetFunc {
+                    wrapper: Wrapper::SomeRef,
+                    ty,
+                    ctor,
+                });
+            }
+        }
+
+        if input.peek(Token![&]) {
+            input.parse::<Token![&]>()?;
+            let (ty, ctor) = WidgetFunc::parse_inner(input)?;
+            return Ok(WidgetFunc {
+                wrapper: Wrapper::Ref,
+                ty,
+                ctor,
+            });
+        }
+
+        let (ty, ctor) = WidgetFunc::parse_inner(input)?;
+        Ok(WidgetFunc {
+            wrapper: Wrapper::None,
+            ty,
+            ctor,
+        })
+    }
+}
+
+/// The value assigned to a plain property, e.g. `set_label: "hi"` or
+/// `set_label: watch! { format!("Counter: {}", model.counter) }`.
+enum PropValue {
+    /// Set once, in `init_view`.
+    Expr(Expr),
+    /// Re-evaluated and re-assigned, unconditionally, on every `view()` call.
+    Watch(Expr),
+    /// Re-evaluated and re-assigned only when one of the named `tracker`-tracked
+    /// fields actually changed, e.g. `track!(model.counter, &format!("{}", model.counter))`.
+    Track(Vec<Ident>, Expr),
+}
+
+impl Parse for PropValue {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        if input.peek(Ident) && input.peek2(Token![!]) {
+            let fork = input.fork();
+            let ident: Ident = fork.parse()?;
+            if ident == "watch" {
+                input.advance_to(&fork);
+                input.parse::<Token![!]>()?;
+                let content;
+                braced!(content in input);
+                return Ok(PropValue::Watch(content.parse()?));
+            }
+        }
+
+        if input.peek(Ident) && input.peek2(Token![!]) {
+            let fork = input.fork();
+            let ident: Ident = fork.parse()?;
+            if ident == "track" {
+                input.advance_to(&fork);
+                input.parse::<Token![!]>()?;
+                let content;
+                parenthesized!(content in input);
+                let mut exprs: Vec<Expr> = Punctuated::<Expr, Token![,]>::parse_terminated(&content)?
+                    .into_iter()
+                    .collect();
+                let value = exprs
+                    .pop()
+                    .ok_or_else(|| content.error("Expected a value expression"))?;
+
+                let mut fields = Vec::new();
+                for expr in exprs {
+                    match expr {
+                        Expr::Field(field) => match field.member {
+                            Member::Named(ident) => fields.push(ident),
+                            Member::Unnamed(index) => {
+                                return Err(Error::new_spanned(
+                                    index,
+                                    "Expected a named field, e.g. `model.counter`",
+                                ))
+                            }
+                        },
+                        other => {
+                            return Err(Error::new_spanned(
+                                other,
+                                "Expected a `model.field` expression naming a tracked field",
+                            ))
+                        }
+                    }
+                }
+
+                return Ok(PropValue::Track(fields, value));
+            }
+        }
+
+        Ok(PropValue::Expr(input.parse()?))
+    }
+}
+
+mod kw {
+    syn::custom_keyword!(construct);
+    syn::custom_keyword!(actions);
+    syn::custom_keyword!(component);
+}
+
+/// A single `name([accels, ..]) => handler` entry inside an `actions(".." ) { .. }` block.
+struct ActionDef {
+    name: Ident,
+    accels: Vec<LitStr>,
+    handler: Expr,
+}
+
+impl Parse for ActionDef {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let name: Ident = input.parse()?;
+        let content;
+        parenthesized!(content in input);
+        let bracket_content;
+        syn::bracketed!(bracket_content in content);
+        let accels = Punctuated::<LitStr, Token![,]>::parse_terminated(&bracket_content)?
+            .into_iter()
+            .collect();
+        input.parse::<Token![=>]>()?;
+        let handler: Expr = input.parse()?;
+        Ok(ActionDef {
+            name,
+            accels,
+            handler,
+        })
+    }
+}
+
+/// A child component attached via `relation = component!(expr[, container =
+/// "name"[, container_type = Type]])`, e.g. `append = component!(components.sidebar)`
+/// or `set_title_widget = component!(components.header, container = "titlebar",
+/// container_type = gtk::HeaderBar)`.
+///
+/// `expr` names the component (e.g. `components.dialog`); its root widget is
+/// attached by calling `relation` on either this widget or, if `container` is
+/// given, the widget registered under that name via `#[container = ".."]`.
+/// Since `container_widget` returns a type-erased `gtk::Widget`, attaching
+/// through a container also needs to know its concrete type to downcast to
+/// before `relation` can be called on it; `container_type` defaults to
+/// `gtk::Box` (the common case) and must be given explicitly for anything
+/// else, e.g. a `gtk::HeaderBar` insertion point.
+struct ComponentDef {
+    expr: Expr,
+    container: Option<LitStr>,
+    container_ty: Option<Path>,
+}
+
+impl Parse for ComponentDef {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let expr: Expr = input.parse()?;
+        let mut container = None;
+        let mut container_ty = None;
+
+        while input.peek(Token![,]) {
+            input.parse::<Token![,]>()?;
+            let ident: Ident = input.parse()?;
+            input.parse::<Token![=]>()?;
+            match ident.to_string().as_str() {
+                "container" => container = Some(input.parse()?),
+                "container_type" => container_ty = Some(input.parse()?),
+                other => {
+                    return Err(Error::new_spanned(
+                        ident,
+                        format!("Unknown `component!` argument `{}`", other),
+                    ))
+                }
+            }
+        }
+
+        Ok(ComponentDef {
+            expr,
+            container,
+            container_ty,
+        })
+    }
+}
+
+/// A single `name: value` entry inside a `construct { .. }` block.
+struct ConstructAssign {
+    name: Ident,
+    value: Expr,
+}
+
+impl Parse for ConstructAssign {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let name: Ident = input.parse()?;
+        input.parse::<Token![:]>()?;
+        let value: Expr = input.parse()?;
+        Ok(ConstructAssign { name, value })
+    }
+}
+
+/// One entry inside a widget's `{ .. }` body.
+enum Item {
+    Property(Ident, PropValue),
+    Connect {
+        signal: Ident,
+        args: Punctuated<Ident, Token![,]>,
+        handler: Expr,
+    },
+    Child {
+        relation: Ident,
+        attr: WidgetAttr,
+        name: Option<Ident>,
+        func: WidgetFunc,
+        items: Vec<Item>,
+    },
+    /// Construct-only properties, collected separately so they can be passed to
+    /// `glib::Object::builder` instead of being set through a setter after the fact.
+    Construct(Vec<ConstructAssign>),
+    /// A named `gio::SimpleActionGroup` with its actions, declared next to the
+    /// widget it's inserted into, e.g. `actions("win") { test([..]) => .. }`.
+    Actions(LitStr, Vec<ActionDef>),
+    /// A child component attached via `relation = component!(..)`.
+    Component(Ident, ComponentDef),
+}
+
+impl Parse for Item {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let attrs = input.call(Attribute::parse_outer)?;
+        let mut widget_attr = WidgetAttr::default();
+        for attr in &attrs {
+            let ident = attr.path.get_ident().cloned().ok_or_else(|| {
+                Error::new_spanned(&attr.path, "Expected a simple attribute name")
+            })?;
+            // `#[container = "sidebar"]` is a name-value attribute, not a
+            // delimited one, so it has to be parsed out of `attr.tokens`
+            // (`= "sidebar"`) directly; `Attribute::parse_args` only accepts
+            // `#[container(..)]`/`[..]`/`{..}` and errors on a bare `=`.
+            let value = (|input: ParseStream| -> syn::Result<LitStr> {
+                input.parse::<Token![=]>()?;
+                input.parse()
+            })
+            .parse2(attr.tokens.clone())?;
+            widget_attr.merge(&ident, value)?;
+        }
+
+        if input.peek(kw::construct) {
+            input.parse::<kw::construct>()?;
+            let content;
+            braced!(content in input);
+            let assigns = Punctuated::<ConstructAssign, Token![,]>::parse_terminated(&content)?;
+            return Ok(Item::Construct(assigns.into_iter().collect()));
+        }
+
+        if input.peek(kw::actions) {
+            input.parse::<kw::actions>()?;
+            let group_content;
+            parenthesized!(group_content in input);
+            let group: LitStr = group_content.parse()?;
+            let body_content;
+            braced!(body_content in input);
+            let actions = Punctuated::<ActionDef, Token![,]>::parse_terminated(&body_content)?
+                .into_iter()
+                .collect();
+            return Ok(Item::Actions(group, actions));
+        }
+
+        let ident: Ident = input.parse()?;
+
+        if input.peek(token::Paren) {
+            let content;
+            parenthesized!(content in input);
+            let args = Punctuated::<Ident, Token![,]>::parse_terminated(&content)?;
+            input.parse::<Token![=>]>()?;
+            let handler: Expr = input.parse()?;
+            return Ok(Item::Connect {
+                signal: ident,
+                args,
+                handler,
+            });
+        }
+
+        if input.peek(Token![:]) {
+            input.parse::<Token![:]>()?;
+            let value: PropValue = input.parse()?;
+            return Ok(Item::Property(ident, value));
+        }
+
+        if input.peek(Token![=]) {
+            input.parse::<Token![=]>()?;
+
+            if input.peek(kw::component) {
+                input.parse::<kw::component>()?;
+                input.parse::<Token![!]>()?;
+                let content;
+                parenthesized!(content in input);
+                return Ok(Item::Component(ident, content.parse()?));
+            }
+
+            let func: WidgetFunc = input.parse()?;
+            let content;
+            braced!(content in input);
+            let items = Punctuated::<Item, Token![,]>::parse_terminated(&content)?
+                .into_iter()
+                .collect();
+            return Ok(Item::Child {
+                relation: ident,
+                attr: widget_attr,
+                name: None,
+                func,
+                items,
+            });
+        }
+
+        Err(input.error("Expected `:`, `(..)` or `=` after an identifier"))
+    }
+}
+
+fn default_name(func: &WidgetFunc) -> Ident {
+    let idents: Vec<Ident> = func
+        .ty
+        .segments
+        .iter()
+        .map(|segment| segment.ident.clone())
+        .collect();
+    idents_to_snake_case(&idents)
+}
+
+/// How a widget is attached to its parent: the parent's variable name and the
+/// method called on it, e.g. `(main_window, set_child)`.
+#[derive(Clone)]
+pub(super) struct Attach {
+    pub parent: Ident,
+    pub relation: Ident,
+}
+
+pub(super) struct Widget {
+    pub name: Ident,
+    pub func: WidgetFunc,
+    pub attr: WidgetAttr,
+    attach: Option<Attach>,
+    properties: Vec<(Ident, PropValue)>,
+    connects: Vec<(Ident, Punctuated<Ident, Token![,]>, Expr)>,
+    construct: Vec<ConstructAssign>,
+    actions: Vec<(LitStr, Vec<ActionDef>)>,
+    components: Vec<(Ident, ComponentDef)>,
+    children: Vec<Widget>,
+}
+
+impl Widget {
+    fn from_items(
+        name: Ident,
+        func: WidgetFunc,
+        attr: WidgetAttr,
+        attach: Option<Attach>,
+        items: Vec<Item>,
+    ) -> Self {
+        let mut widget = Widget {
+            name,
+            func,
+            attr,
+            attach,
+            properties: Vec::new(),
+            connects: Vec::new(),
+            construct: Vec::new(),
+            actions: Vec::new(),
+            components: Vec::new(),
+            children: Vec::new(),
+        };
+
+        for item in items {
+            match item {
+                Item::Property(ident, value) => widget.properties.push((ident, value)),
+                Item::Connect {
+                    signal,
+                    args,
+                    handler,
+                } => widget.connects.push((signal, args, handler)),
+                Item::Construct(assigns) => widget.construct.extend(assigns),
+                Item::Actions(group, actions) => widget.actions.push((group, actions)),
+                Item::Component(relation, component) => {
+                    widget.components.push((relation, component))
+                }
+                Item::Child {
+                    relation,
+                    attr,
+                    name,
+                    func,
+                    items,
+                } => {
+                    let child_name = name.unwrap_or_else(|| default_name(&func));
+                    let attach = Attach {
+                        parent: widget.name.clone(),
+                        relation,
+                    };
+                    widget
+                        .children
+                        .push(Widget::from_items(child_name, func, attr, Some(attach), items));
+                }
+            }
+        }
+
+        widget
+    }
+
+    /// Flattens this widget and all of its descendants into `list`, in the order
+    /// their local variables must be created (parents before children).
+    pub(super) fn get_widget_list(&self, list: &mut Vec<WidgetRef<'_>>) {
+        list.push(WidgetRef {
+            name: self.name.clone(),
+            func: &self.func,
+            attr: self.attr.clone(),
+            attach: self.attach.clone(),
+            properties: &self.properties,
+            connects: &self.connects,
+            construct: &self.construct,
+            actions: &self.actions,
+            components: &self.components,
+        });
+        for child in &self.children {
+            child.get_widget_list(list);
+        }
+    }
+}
+
+impl Parse for Widget {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let name = if input.peek(Ident) && input.peek2(Token![=]) {
+            let ident: Ident = input.parse()?;
+            input.parse::<Token![=]>()?;
+            Some(ident)
+        } else {
+            None
+        };
+
+        let func: WidgetFunc = input.parse()?;
+        let content;
+        braced!(content in input);
+        let items: Vec<Item> = Punctuated::<Item, Token![,]>::parse_terminated(&content)?
+            .into_iter()
+            .collect();
+
+        let name = name.unwrap_or_else(|| default_name(&func));
+        Ok(Widget::from_items(
+            name,
+            func,
+            WidgetAttr::default(),
+            None,
+            items,
+        ))
+    }
+}
+
+/// A flattened view of a single widget, borrowed out of the tree in
+/// [`Widget::get_widget_list`]. Each one contributes one local variable, one
+/// struct field and a handful of token streams to the generated `Widgets` impl.
+pub(super) struct WidgetRef<'a> {
+    pub name: Ident,
+    pub func: &'a WidgetFunc,
+    pub attr: WidgetAttr,
+    attach: Option<Attach>,
+    properties: &'a [(Ident, PropValue)],
+    connects: &'a [(Ident, Punctuated<Ident, Token![,]>, Expr)],
+    construct: &'a [ConstructAssign],
+    actions: &'a [(LitStr, Vec<ActionDef>)],
+    components: &'a [(Ident, ComponentDef)],
+}
+
+impl<'a> WidgetRef<'a> {
+    /// The `field_name,` shorthand used when building `Self { .. }`.
+    pub(super) fn return_stream(&self) -> TokenStream2 {
+        let name = &self.name;
+        quote! { #name, }
+    }
+
+    /// The `let #name = ..;` binding that constructs this widget. Construct-only
+    /// properties lower into a single `glib::Object::builder` call that replaces
+    /// the regular constructor; everything else is assigned afterwards through
+    /// ordinary setter calls in [`Self::property_assign_stream`].
+    pub(super) fn init_stream(&self) -> TokenStream2 {
+        let name = &self.name;
+
+        if self.construct.is_empty() {
+            let func = self.func.func_token_stream();
+            quote! { let #name = #func; }
+        } else {
+            let ty = self.func.type_token_stream();
+            // GObject property names are kebab-case; Rust identifiers can't contain
+            // `-`, so `default_width` in `construct { .. }` must become `"default-width"`.
+            let names = self
+                .construct
+                .iter()
+                .map(|assign| assign.name.to_string().replace('_', "-"));
+            let values = self.construct.iter().map(|assign| &assign.value);
+            quote! {
+                let #name: #ty = gtk::glib::Object::builder::<#ty>()
+                    #(.property(#names, #values))*
+                    .build();
+            }
+        }
+    }
+
+    /// Property assignments and the call that attaches this widget to its parent.
+    pub(super) fn property_assign_stream(&self, _relm4_path: &Path, tokens: &mut TokenStream2) {
+        let name = &self.name;
+
+        if let Some(attach) = &self.attach {
+            let parent = &attach.parent;
+            let relation = &attach.relation;
+            let wrapped = self.func.wrap(name);
+            tokens.extend(quote! { #parent.#relation(#wrapped); });
+        }
+
+        for (prop, value) in self.properties {
+            if let PropValue::Expr(expr) = value {
+                tokens.extend(quote! { #name.#prop(#expr); });
+            }
+        }
+    }
+
+    /// Emits nothing; `watch!` and `track!` updates are generated by [`Self::track_stream`].
+    pub(super) fn view_stream(&self, _relm4_path: &Path, _tokens: &mut TokenStream2) {}
+
+    pub(super) fn connect_stream(&self) -> TokenStream2 {
+        let name = &self.name;
+        let mut tokens = TokenStream2::new();
+
+        for (signal, args, handler) in self.connects {
+            let args: Vec<&Ident> = args.iter().collect();
+            tokens.extend(quote! {
+                #(let #args = #args.clone();)*
+                #name.#signal(#handler);
+            });
+        }
+
+        tokens
+    }
+
+    /// Lowers every `actions("group") { name([accels]) => handler, .. }` block
+    /// declared on this widget into a `gio::SimpleActionGroup`, one `SimpleAction`
+    /// per entry, its accelerators, and `insert_action_group` on this widget.
+    /// `ActionMapExt`/`GtkApplicationExt` calls are fully qualified (like
+    /// `container_stream`'s `AsRef` call) so the caller's imports don't matter.
+    pub(super) fn action_stream(&self, relm4_path: &Path) -> TokenStream2 {
+        let name = &self.name;
+        let mut tokens = TokenStream2::new();
+
+        for (group_name, actions) in self.actions {
+            let mut group_tokens = TokenStream2::new();
+
+            for action in actions {
+                let action_name = action.name.to_string();
+                let full_action_name = format!("{}.{}", group_name.value(), action_name);
+                let handler = &action.handler;
+                let accels = &action.accels;
+
+                group_tokens.extend(quote! {
+                    let action = gtk::gio::SimpleAction::new(#action_name, None);
+                    action.connect_activate(#handler);
+                    gtk::prelude::GtkApplicationExt::set_accels_for_action(&#relm4_path::gtk_application(), #full_action_name, &[#(#accels),*]);
+                    gtk::gio::prelude::ActionMapExt::add_action(&__action_group, &action);
+                });
+            }
+
+            tokens.extend(quote! {
+                {
+                    let __action_group = gtk::gio::SimpleActionGroup::new();
+                    #group_tokens
+                    #name.insert_action_group(#group_name, Some(&__action_group));
+                }
+            });
+        }
+
+        tokens
+    }
+
+    /// `watch! { .. }` properties are re-assigned unconditionally on every
+    /// `view()` call. `track!(model.field, ..)` properties are only re-assigned
+    /// when `model.changed(..)` reports that one of the named fields was
+    /// actually touched since the last `model.reset()` (which is the caller's
+    /// responsibility to call after `view()`, as usual for the `tracker` crate).
+    pub(super) fn track_stream(&self, model_ty: &syn::Type) -> TokenStream2 {
+        let name = &self.name;
+        let mut tokens = TokenStream2::new();
+
+        for (prop, value) in self.properties {
+            match value {
+                PropValue::Watch(expr) => {
+                    tokens.extend(quote! { self.#name.#prop(#expr); });
+                }
+                PropValue::Track(fields, expr) => {
+                    let masks = fields.iter().map(|field| quote! { #model_ty::#field() });
+                    tokens.extend(quote! {
+                        if model.changed(#(#masks)|*) {
+                            self.#name.#prop(#expr);
+                        }
+                    });
+                }
+                PropValue::Expr(_) => {}
+            }
+        }
+
+        tokens
+    }
+
+    /// Attaches every `relation = component!(expr[, container = "name"[,
+    /// container_type = Type]])` declared on this widget: the component's
+    /// root widget is attached by calling `relation` on either the named
+    /// `#[container = ".."]` insertion point (looked up through
+    /// `container_widget` and downcast to `container_type`, `gtk::Box` by
+    /// default) or this widget directly when no container is named. Neither
+    /// the attach method nor the container's type is hardcoded, so insertion
+    /// points that aren't `gtk::Box` (a header bar's title widget, for
+    /// example) work by naming the right `relation`/`container_type` pair.
+    pub(super) fn component_stream(&self) -> TokenStream2 {
+        let name = &self.name;
+        let mut tokens = TokenStream2::new();
+
+        for (relation, component) in self.components {
+            let expr = &component.expr;
+            let root = quote! { #expr.root_widget() };
+
+            tokens.extend(if let Some(container) = &component.container {
+                let container_ty = component
+                    .container_ty
+                    .clone()
+                    .unwrap_or_else(|| syn::parse_quote! { gtk::Box });
+
+                quote! {
+                    gtk::glib::Cast::downcast::<#container_ty>(
+                        self.container_widget(#container)
+                            .expect("No widget is registered for this container name"),
+                    )
+                    .expect("Container widget did not match the declared container_type")
+                    .#relation(&#root);
+                }
+            } else {
+                quote! { self.#name.#relation(&#root); }
+            });
+        }
+
+        tokens
+    }
+
+    /// Deliberately unimplemented: this request's `component!` syntax only
+    /// describes how a component's root widget is attached (handled by
+    /// [`Self::component_stream`]), it has no clause for forwarding a
+    /// component's own output messages back to the parent. That's a
+    /// separate, still-undesigned piece of DSL surface (what the forwarding
+    /// closure looks like, how it's named, how it interacts with `Sender`),
+    /// so this stays an explicit no-op rather than guessing at one.
+    pub(super) fn connect_component_stream(&self) -> TokenStream2 {
+        TokenStream2::new()
+    }
+
+    /// One `"name" => Some(..)` match arm for `container_widget`, if this widget
+    /// was annotated with `#[container = "name"]`.
+    pub(super) fn container_stream(&self) -> TokenStream2 {
+        if let Some(container_name) = &self.attr.container {
+            let name = &self.name;
+            quote! {
+                #container_name => Some(::std::convert::AsRef::<gtk::Widget>::as_ref(&self.#name).clone()),
+            }
+        } else {
+            TokenStream2::new()
+        }
+    }
+}